@@ -2,9 +2,53 @@
 //! Submodule for interacting with btrfs filesystems
 //!
 //! Currently only implements formatting btrfs filesystems
-#![allow(unused_imports)]
 
+pub mod backend;
+pub mod check;
+#[cfg(feature = "fatfs")]
+pub mod fat;
 pub mod format;
-use std::path::{Path, PathBuf};
-use sys_mount::{Mount, MountBuilder, MountFlags};
-pub fn mount(_device: &Path, _destination: &Path) {}
+pub mod formatter;
+pub mod scrub;
+use std::path::Path;
+use sys_mount::{FilesystemType, Mount, MountBuilder, MountFlags, SupportedFilesystems};
+
+/// Mounts `device` at `destination`, auto-detecting its filesystem type instead
+/// of assuming btrfs, so a device formatted with something else still mounts.
+/// `flags` carries kernel mount flags (e.g. `RDONLY`, `NOEXEC`, `NODEV`), and
+/// `data` is forwarded verbatim to the kernel as the mount options string
+/// (e.g. `"subvol=@home,compress=zstd"`). Returns the fstype that was actually
+/// detected.
+pub fn mount(
+    device: &Path,
+    destination: &Path,
+    flags: MountFlags,
+    data: Option<&str>,
+) -> crate::Result<String> {
+    validate_flags(flags)?;
+    let supported = SupportedFilesystems::new()?;
+    let mut builder: MountBuilder = Mount::builder()
+        .fstype(FilesystemType::Auto(&supported))
+        .flags(flags);
+    if let Some(data) = data {
+        builder = builder.data(data);
+    }
+    let mount = builder.mount(device, destination)?;
+    Ok(mount.get_fstype().to_owned())
+}
+
+/// Rejects combinations of [`MountFlags`] that contradict each other, instead
+/// of letting the kernel reject them (or silently pick one) at mount time.
+fn validate_flags(flags: MountFlags) -> crate::Result<()> {
+    if flags.contains(MountFlags::BIND) && flags.contains(MountFlags::REMOUNT) {
+        return Err(crate::Error::ArgumentError(
+            "mount flags BIND and REMOUNT are mutually exclusive".to_owned(),
+        ));
+    }
+    if flags.contains(MountFlags::NOATIME) && flags.contains(MountFlags::RELATIME) {
+        return Err(crate::Error::ArgumentError(
+            "mount flags NOATIME and RELATIME are mutually exclusive".to_owned(),
+        ));
+    }
+    Ok(())
+}
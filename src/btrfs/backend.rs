@@ -0,0 +1,90 @@
+//! # Backend capability detection
+//! `Formatter::format` execs `mkfs.btrfs` blindly, which means a missing binary
+//! surfaces as a confusing [`std::io::ErrorKind::NotFound`] and options that a
+//! newer `btrfs-progs` added fail opaquely mid-format. `Backend::probe` runs
+//! `mkfs.btrfs --version` up front and parses it into a [`Capabilities`], so
+//! callers (and [`super::format::FormatterOptions::finalize`]) can ask
+//! "can this host format with the options I want?" before touching a device.
+
+use crate::{Error, Result};
+use std::process::Command;
+
+/// A `(major, minor, patch)` version triple parsed from `mkfs.btrfs --version`.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Version(pub u32, pub u32, pub u32);
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+/// What the detected `mkfs.btrfs` binary can do.
+#[derive(Clone, Copy, Debug)]
+pub struct Capabilities {
+    pub version: Version,
+}
+
+impl Capabilities {
+    /// `--runtime-features` was added in btrfs-progs 4.9.
+    const RUNTIME_FEATURES_MIN: Version = Version(4, 9, 0);
+    /// `--checksum xxhash|sha256|blake2` were added in btrfs-progs 5.4; `crc32c`
+    /// has always been supported.
+    const EXTRA_CHECKSUMS_MIN: Version = Version(5, 4, 0);
+
+    /// Checks whether `--runtime-features` is supported, returning the minimum
+    /// required version as the error if not.
+    pub fn check_runtime_features(&self) -> std::result::Result<(), Version> {
+        if self.version >= Self::RUNTIME_FEATURES_MIN {
+            Ok(())
+        } else {
+            Err(Self::RUNTIME_FEATURES_MIN)
+        }
+    }
+
+    /// Checks whether `checksum` is supported, returning the minimum required
+    /// version as the error if not.
+    pub fn check_checksum(
+        &self,
+        checksum: super::format::ChecksumAlgorithm,
+    ) -> std::result::Result<(), Version> {
+        use super::format::ChecksumAlgorithm::*;
+        match checksum {
+            Crc32c => Ok(()),
+            XxHash | Sha256 | Blake2 if self.version >= Self::EXTRA_CHECKSUMS_MIN => Ok(()),
+            XxHash | Sha256 | Blake2 => Err(Self::EXTRA_CHECKSUMS_MIN),
+        }
+    }
+}
+
+/// Detects the installed `mkfs.btrfs` backend.
+pub struct Backend;
+
+impl Backend {
+    /// Runs `mkfs.btrfs --version` and parses the reported version into
+    /// [`Capabilities`].
+    pub fn probe() -> Result<Capabilities> {
+        let output = Command::new("mkfs.btrfs").arg("--version").output()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        let version = parse_version(&text).ok_or_else(|| {
+            Error::ArgumentError(format!(
+                "couldn't parse `mkfs.btrfs --version` output: {text:?}"
+            ))
+        })?;
+        Ok(Capabilities { version })
+    }
+}
+
+/// Parses e.g. `"mkfs.btrfs, part of btrfs-progs v6.6.3"` into `Version(6, 6, 3)`.
+fn parse_version(text: &str) -> Option<Version> {
+    let version_str = text.split_whitespace().find_map(|word| {
+        let word = word.trim_start_matches('v');
+        word.starts_with(|c: char| c.is_ascii_digit())
+            .then(|| word.trim_end_matches(|c: char| !c.is_ascii_digit()))
+    })?;
+    let mut parts = version_str.splitn(3, '.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    let patch = parts.next().unwrap_or("0").parse().ok()?;
+    Some(Version(major, minor, patch))
+}
@@ -0,0 +1,141 @@
+//! # Btrfs Checker
+//! A wrapper around `btrfs check`, mirroring the `FormatterOptions` -> `Formatter`
+//! builder pattern in [`super::format`].
+//!
+//! Also home to `dump_super`/`metadata_dump`/`restore`, the standard
+//! "snapshot metadata, repair a copy, compare" recovery workflow.
+
+use crate::{Error::ArgumentError, Result};
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// ### CheckerOptions
+/// Representation of options for [`btrfs check`](https://btrfs.readthedocs.io/en/latest/btrfs-check.html).
+#[derive(Clone, Debug, Default)]
+pub struct CheckerOptions {
+    readonly: bool,
+    repair: bool,
+    init_csum_tree: bool,
+    init_extent_tree: bool,
+    check_data_csum: bool,
+    subvol_extents: Option<u64>,
+}
+
+impl CheckerOptions {
+    /// Run read-only (the safe default): report problems without changing anything.
+    pub fn readonly(mut self) -> Self {
+        self.readonly = true;
+        self
+    }
+    /// Attempt to repair the filesystem. Destructive, and mutually exclusive
+    /// with [`CheckerOptions::readonly`].
+    pub fn repair(mut self) -> Self {
+        self.repair = true;
+        self
+    }
+    /// Rebuild the checksum tree from scratch.
+    pub fn init_csum_tree(mut self) -> Self {
+        self.init_csum_tree = true;
+        self
+    }
+    /// Rebuild the extent tree from scratch.
+    pub fn init_extent_tree(mut self) -> Self {
+        self.init_extent_tree = true;
+        self
+    }
+    /// Verify data checksums in addition to metadata.
+    pub fn check_data_csum(mut self) -> Self {
+        self.check_data_csum = true;
+        self
+    }
+    /// Check the extent references of a single subvolume, by id.
+    pub fn subvol_extents(mut self, subvol_id: u64) -> Self {
+        self.subvol_extents = Some(subvol_id);
+        self
+    }
+
+    fn to_args(&self) -> Vec<OsString> {
+        let mut args = vec![];
+        if self.readonly {
+            args.push(OsString::from("--readonly"));
+        }
+        if self.repair {
+            args.push(OsString::from("--repair"));
+        }
+        if self.init_csum_tree {
+            args.push(OsString::from("--init-csum-tree"));
+        }
+        if self.init_extent_tree {
+            args.push(OsString::from("--init-extent-tree"));
+        }
+        if self.check_data_csum {
+            args.push(OsString::from("--check-data-csum"));
+        }
+        if let Some(subvol_id) = self.subvol_extents {
+            args.push(OsString::from(format!("--subvol-extents={subvol_id}")));
+        }
+        args
+    }
+
+    /// Bake `CheckerOptions` into a `Checker`, refusing to combine `repair` with
+    /// `readonly` (running repair against a read-only-intended device is never safe).
+    pub fn finalize(&self) -> Result<Checker> {
+        if self.readonly && self.repair {
+            return Err(ArgumentError(
+                "btrfs check: --readonly and --repair are mutually exclusive".to_owned(),
+            ));
+        }
+        Ok(Checker {
+            args: self.to_args(),
+        })
+    }
+}
+
+/// ### Checker
+/// A rusty-ish wrapper for `btrfs check`.
+#[derive(Debug)]
+pub struct Checker {
+    args: Vec<OsString>,
+}
+
+impl Checker {
+    pub fn options() -> CheckerOptions {
+        CheckerOptions::default()
+    }
+
+    /// Runs `btrfs check` against `device`.
+    pub fn check(mut self, device: &Path) -> Result<Output> {
+        self.args.push(OsString::from(device));
+        Ok(Command::new("btrfs")
+            .arg("check")
+            .args(self.args)
+            .output()?)
+    }
+}
+
+/// Runs `btrfs inspect-internal dump-super` against `device`.
+pub fn dump_super(device: &Path) -> Result<Output> {
+    Ok(Command::new("btrfs")
+        .args(["inspect-internal", "dump-super"])
+        .arg(device)
+        .output()?)
+}
+
+/// Captures `device`'s metadata to `image_path` via `btrfs-image`, so it can be
+/// repaired on a copy and compared against the original.
+pub fn metadata_dump(device: &Path, image_path: &Path) -> Result<Output> {
+    Ok(Command::new("btrfs-image")
+        .arg(device)
+        .arg(image_path)
+        .output()?)
+}
+
+/// Replays a metadata image captured by [`metadata_dump`] back into `device`.
+pub fn restore(image_path: &Path, device: &Path) -> Result<Output> {
+    Ok(Command::new("btrfs-image")
+        .arg("-r")
+        .arg(image_path)
+        .arg(device)
+        .output()?)
+}
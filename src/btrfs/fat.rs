@@ -0,0 +1,146 @@
+//! # FAT formatter
+//! A pure-Rust [`FilesystemFormatter`](super::formatter::FilesystemFormatter)
+//! backend built on the [`fatfs`] crate, so a FAT12/16/32 image can be produced
+//! without `mkfs.vfat`, root, or a kernel module.
+
+use super::formatter::FilesystemFormatter;
+use crate::{Error, Result};
+use fatfs::{FatType, FileSystem, FormatVolumeOptions, FsOptions};
+use std::fs::File;
+use std::io;
+use std::os::unix::process::ExitStatusExt;
+use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Output};
+
+/// Options for [`FatFormatter`], mirroring [`super::format::FormatterOptions`]'s
+/// builder shape but for the in-process FAT backend.
+#[derive(Clone, Debug, Default)]
+pub struct FatFormatterOptions {
+    fat_type: Option<FatType>,
+    label: String,
+    bytes_per_sector: Option<u16>,
+    byte_count: Option<u64>,
+    rootdir: Option<PathBuf>,
+}
+
+impl FatFormatterOptions {
+    /// Forces FAT12, FAT16, or FAT32 instead of letting the volume size decide.
+    pub fn fat_type(mut self, fat_type: FatType) -> Result<Self> {
+        self.fat_type = Some(fat_type);
+        Ok(self)
+    }
+    /// Sets the volume label (truncated/padded to 11 bytes, as FAT requires).
+    pub fn label(mut self, label: &str) -> Result<Self> {
+        self.label = label.to_owned();
+        Ok(self)
+    }
+    /// Sets the sector size, in bytes.
+    pub fn bytes_per_sector(mut self, bytes_per_sector: u16) -> Result<Self> {
+        self.bytes_per_sector = Some(bytes_per_sector);
+        Ok(self)
+    }
+    /// Sets the total size of the image, in bytes.
+    pub fn byte_count(mut self, byte_count: u64) -> Result<Self> {
+        self.byte_count = Some(byte_count);
+        Ok(self)
+    }
+    /// A directory whose contents get copied into the new filesystem's root.
+    pub fn rootdir(mut self, rootdir: PathBuf) -> Result<Self> {
+        rootdir.try_exists()?;
+        self.rootdir = Some(rootdir);
+        Ok(self)
+    }
+
+    pub fn finalize(&self) -> FatFormatter {
+        FatFormatter {
+            options: self.clone(),
+        }
+    }
+}
+
+/// ### FatFormatter
+/// Formats a FAT12/16/32 image entirely in-process via [`fatfs`].
+#[derive(Clone, Debug)]
+pub struct FatFormatter {
+    options: FatFormatterOptions,
+}
+
+impl FatFormatter {
+    pub fn options() -> FatFormatterOptions {
+        FatFormatterOptions::default()
+    }
+
+    /// Pads/truncates a label to the 11 bytes FAT volume labels require.
+    fn volume_label(&self) -> [u8; 11] {
+        let mut label = [b' '; 11];
+        let bytes = self.options.label.as_bytes();
+        let len = bytes.len().min(11);
+        label[..len].copy_from_slice(&bytes[..len]);
+        label
+    }
+
+    fn copy_rootdir(fs: &FileSystem<File>, rootdir: &Path, dest: &str) -> Result<()> {
+        for entry in std::fs::read_dir(rootdir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            let dest_path = format!("{}/{}", dest.trim_end_matches('/'), name);
+
+            if entry.file_type()?.is_dir() {
+                fs.root_dir().create_dir(&dest_path)?;
+                Self::copy_rootdir(fs, &entry.path(), &dest_path)?;
+            } else {
+                let mut dest_file = fs.root_dir().create_file(&dest_path)?;
+                let mut source_file = File::open(entry.path())?;
+                io::copy(&mut source_file, &mut dest_file)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FilesystemFormatter for FatFormatter {
+    fn label(&self) -> &str {
+        let label = &self.options.label;
+        let mut len = label.len().min(11);
+        while !label.is_char_boundary(len) {
+            len -= 1;
+        }
+        &label[..len]
+    }
+
+    fn rootdir(&self) -> Option<&Path> {
+        self.options.rootdir.as_deref()
+    }
+
+    fn format(self, device: &Path) -> Result<Output> {
+        let byte_count = self
+            .options
+            .byte_count
+            .ok_or_else(|| Error::ArgumentError("FatFormatter requires a byte_count".to_owned()))?;
+
+        let file = File::options().read(true).write(true).open(device)?;
+        file.set_len(byte_count)?;
+
+        let mut format_options = FormatVolumeOptions::new().volume_label(self.volume_label());
+        if let Some(fat_type) = self.options.fat_type {
+            format_options = format_options.fat_type(fat_type);
+        }
+        if let Some(bytes_per_sector) = self.options.bytes_per_sector {
+            format_options = format_options.bytes_per_sector(bytes_per_sector);
+        }
+        fatfs::format_volume(&file, format_options)?;
+
+        let fs = FileSystem::new(file, FsOptions::new())?;
+        if let Some(rootdir) = self.options.rootdir.clone() {
+            Self::copy_rootdir(&fs, &rootdir, "")?;
+        }
+        fs.unmount()?;
+
+        Ok(Output {
+            status: ExitStatus::from_raw(0),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        })
+    }
+}
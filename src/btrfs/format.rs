@@ -21,6 +21,7 @@
 //!     // These are all optional
 //!     .byte_count(536_870_912_u64).unwrap()
 //!     .checksum(CRC32C).unwrap()
+//!     .create_backing_file(536_870_912_u64).unwrap()
 //!     .data(DataProfile::Dup).unwrap()
 //!     .features(["mixed-bg"]).unwrap()
 //!     .force().unwrap()      // true if called
@@ -35,7 +36,7 @@
 //!     .shrink().unwrap()     // true if called
 //!     .uuid("73e1b7e2-a3a8-49c2-b258-06f01a889bba").unwrap()
 //!     // build the Formatter
-//!     .finalize();
+//!     .finalize().unwrap();
 //! // Format a device
 //! formatter.format(&PathBuf::from("./test.btrfs")).unwrap();
 //! ```
@@ -47,7 +48,6 @@ use crate::{
 use std::{
     ffi::{OsStr, OsString},
     fmt::{write, Display},
-    io::Result as IoResult,
     path::{Path, PathBuf},
     process::{Command, Output},
 };
@@ -73,6 +73,19 @@ pub enum DataProfile {
     Dup,
 }
 
+impl DataProfile {
+    /// The minimum number of devices btrfs requires for this profile.
+    pub fn min_devices(self) -> usize {
+        use DataProfile::*;
+        match self {
+            Raid0 | Raid1 | Raid5 => 2,
+            Raid1c3 | Raid6 => 3,
+            Raid1c4 | Raid10 => 4,
+            Single | Dup => 1,
+        }
+    }
+}
+
 impl std::fmt::Display for DataProfile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use DataProfile::*;
@@ -152,6 +165,22 @@ pub struct FormatterOptions {
     sectorsize: Option<OsString>,       // Uint
     shrink: Option<OsString>,           // Bool
     uuid: Option<OsString>,             // Uuid
+    /// Mirrors `label`, kept untransformed for [`FilesystemFormatter::label`].
+    label_value: Option<String>,
+    /// Mirrors `rootdir`, kept untransformed for [`FilesystemFormatter::rootdir`].
+    rootdir_value: Option<PathBuf>,
+    /// Mirrors `byte_count`, kept untransformed so [`Formatter::format`] can
+    /// validate it against the size of a backing file it creates.
+    byte_count_value: Option<u64>,
+    /// Size to create the backing file at, if it doesn't already exist.
+    create_backing_file_size: Option<u64>,
+    /// Mirrors `data`, kept untransformed to validate device counts against.
+    data_value: Option<DataProfile>,
+    /// Mirrors `metadata`, kept untransformed to validate device counts against.
+    metadata_value: Option<DataProfile>,
+    /// Mirrors `checksum`, kept untransformed so [`FormatterOptions::finalize`]
+    /// can check it against the detected backend's [`super::backend::Capabilities`].
+    checksum_value: Option<ChecksumAlgorithm>,
 }
 
 impl FormatterOptions {
@@ -166,6 +195,22 @@ impl FormatterOptions {
     /// ```
     pub fn byte_count(mut self, byte_count: u64) -> Result<Self> {
         self.byte_count = Some(OsString::from(format!("--byte-count={byte_count}")));
+        self.byte_count_value = Some(byte_count);
+        Ok(self)
+    }
+    /// If the target device doesn't already exist, create it as a sparse file of
+    /// `size` bytes before formatting, instead of requiring it be pre-created
+    /// with `truncate`/`dd`.
+    ///
+    /// # Example:
+    /// ```
+    /// use overmount::btrfs::format::Formatter;
+    /// Formatter::options()
+    ///     .create_backing_file(536_870_912_u64)
+    ///     .unwrap();
+    /// ```
+    pub fn create_backing_file(mut self, size: u64) -> Result<Self> {
+        self.create_backing_file_size = Some(size);
         Ok(self)
     }
     /// Specify the checksum algorithm (as ChecksumAlgorithm.)
@@ -182,6 +227,7 @@ impl FormatterOptions {
     /// ```
     pub fn checksum(mut self, checksum: ChecksumAlgorithm) -> Result<Self> {
         self.checksum = Some(OsString::from(format!("--checksum={checksum}")));
+        self.checksum_value = Some(checksum);
         Ok(self)
     }
     /// Specify the profile for data block groups (as DataProfile.)
@@ -195,6 +241,7 @@ impl FormatterOptions {
     /// ```
     pub fn data(mut self, data: DataProfile) -> Result<Self> {
         self.data = Some(OsString::from(format!("--data={data}")));
+        self.data_value = Some(data);
         Ok(self)
     }
     /// Set mkfs-time features. Unset features by prefixing them with '^'.
@@ -250,6 +297,7 @@ impl FormatterOptions {
             )));
         }
         self.label = Some(OsString::from(format!("--label={label}")));
+        self.label_value = Some(label.to_owned());
         Ok(self)
     }
     /// Specify the profile for metadata block groups (as DataProfile.)
@@ -263,6 +311,7 @@ impl FormatterOptions {
     /// ```
     pub fn metadata(mut self, metadata: DataProfile) -> Result<Self> {
         self.metadata = Some(OsString::from(format!("--metadata={metadata}")));
+        self.metadata_value = Some(metadata);
         Ok(self)
     }
     /// Enable mixing of data and metadata blocks
@@ -326,6 +375,7 @@ impl FormatterOptions {
         // make sure the rootdir is a valid Path
         rootdir.try_exists()?;
 
+        self.rootdir_value = Some(rootdir.clone());
         let rootdir = format!("--rootdir={}", rootdir.display());
         self.rootdir = Some(OsString::from(rootdir));
         Ok(self)
@@ -441,7 +491,10 @@ impl FormatterOptions {
         self
     }
 
-    /// Bake FormatterOptions into a Formatter
+    /// Bake FormatterOptions into a Formatter, checking any options that
+    /// depend on a newer `btrfs-progs` (`runtime_features`, and `checksum`
+    /// algorithms other than `crc32c`) against the backend actually detected
+    /// on this host via [`super::backend::Backend::probe`].
     ///
     /// # Example:
     /// ```
@@ -451,11 +504,44 @@ impl FormatterOptions {
     ///     .label("my-Btrfs-volume").unwrap()
     ///     .rootdir(PathBuf::from("./testdir")).unwrap()
     ///     .shrink().unwrap()
-    ///     .finalize();
+    ///     .finalize().unwrap();
     /// ```
-    pub fn finalize(&self) -> Formatter {
+    pub fn finalize(&self) -> Result<Formatter> {
         let args = self.to_args();
-        Formatter { args }
+        let checksum_needs_probe = matches!(
+            self.checksum_value,
+            Some(ChecksumAlgorithm::XxHash | ChecksumAlgorithm::Sha256 | ChecksumAlgorithm::Blake2)
+        );
+        if self.runtime_features.is_some() || checksum_needs_probe {
+            let capabilities = super::backend::Backend::probe()?;
+            if self.runtime_features.is_some() {
+                capabilities
+                    .check_runtime_features()
+                    .map_err(|required_version| Unsupported {
+                        option: "runtime_features".to_owned(),
+                        required_version: required_version.to_string(),
+                        found_version: capabilities.version.to_string(),
+                    })?;
+            }
+            if let Some(checksum) = self.checksum_value {
+                capabilities
+                    .check_checksum(checksum)
+                    .map_err(|required_version| Unsupported {
+                        option: format!("checksum({checksum})"),
+                        required_version: required_version.to_string(),
+                        found_version: capabilities.version.to_string(),
+                    })?;
+            }
+        }
+        Ok(Formatter {
+            args,
+            label: self.label_value.clone().unwrap_or_default(),
+            rootdir: self.rootdir_value.clone(),
+            byte_count: self.byte_count_value,
+            create_backing_file_size: self.create_backing_file_size,
+            data: self.data_value,
+            metadata: self.metadata_value,
+        })
     }
 }
 
@@ -464,6 +550,12 @@ impl FormatterOptions {
 #[derive(Debug)]
 pub struct Formatter {
     args: Vec<OsString>,
+    label: String,
+    rootdir: Option<PathBuf>,
+    byte_count: Option<u64>,
+    create_backing_file_size: Option<u64>,
+    data: Option<DataProfile>,
+    metadata: Option<DataProfile>,
 }
 
 impl Formatter {
@@ -474,8 +566,9 @@ impl Formatter {
     /// use overmount::btrfs::format::Formatter;
     ///
     /// let options = Formatter::options()
-    /// /* set options here...*/;
-    /// options.finalize().format(&PathBuf::from("./test.btrfs")).unwrap();
+    ///     .create_backing_file(536_870_912_u64).unwrap()
+    /// /* set other options here...*/;
+    /// options.finalize().unwrap().format(&PathBuf::from("./test.btrfs")).unwrap();
     /// ```
     pub fn options() -> FormatterOptions {
         FormatterOptions::default()
@@ -490,12 +583,73 @@ impl Formatter {
     ///     .label("my-Btrfs-volume").unwrap()
     ///     .rootdir(PathBuf::from("./testdir")).unwrap()
     ///     .shrink().unwrap()
-    ///     .finalize()
+    ///     .create_backing_file(536_870_912_u64).unwrap()
+    ///     .finalize().unwrap()
     ///     .format(&PathBuf::from("./test.btrfs")).unwrap();
     /// ```
-    pub fn format(mut self, device: &Path) -> IoResult<Output> {
-        device.try_exists()?;
+    pub fn format(mut self, device: &Path) -> Result<Output> {
+        if !device.try_exists()? {
+            let size = self.create_backing_file_size.ok_or_else(|| {
+                ArgumentError(format!(
+                    "{device:?} doesn't exist; call .create_backing_file(size) to create it"
+                ))
+            })?;
+            if let Some(byte_count) = self.byte_count {
+                if byte_count > size {
+                    return Err(ArgumentError(format!(
+                        "byte_count ({byte_count}) exceeds the backing file size ({size})"
+                    )));
+                }
+            }
+            let file = std::fs::File::create(device)?;
+            file.set_len(size)?;
+        }
         self.args.push(OsString::from(device));
-        Command::new("mkfs.btrfs").args(self.args).output()
+        Ok(Command::new("mkfs.btrfs").args(self.args).output()?)
+    }
+
+    /// Formats a multi-device btrfs array, validating `devices` against
+    /// whatever `data`/`metadata` profile was configured (e.g. `raid10` needs
+    /// at least 4 devices).
+    ///
+    /// # Example:
+    /// ```
+    /// use std::path::PathBuf;
+    /// use overmount::btrfs::format::{DataProfile, Formatter};
+    /// let devices = [PathBuf::from("./a.img"), PathBuf::from("./b.img")];
+    /// let devices: Vec<&std::path::Path> = devices.iter().map(AsRef::as_ref).collect();
+    /// Formatter::options()
+    ///     .data(DataProfile::Raid1).unwrap()
+    ///     .finalize().unwrap()
+    ///     .format_devices(&devices);
+    /// ```
+    pub fn format_devices(mut self, devices: &[&Path]) -> Result<Output> {
+        for profile in [self.data, self.metadata].into_iter().flatten() {
+            let required = profile.min_devices();
+            if devices.len() < required {
+                return Err(ArgumentError(format!(
+                    "{profile} needs at least {required} devices, but only {} were given",
+                    devices.len()
+                )));
+            }
+        }
+        for device in devices {
+            self.args.push(OsString::from(device));
+        }
+        Ok(Command::new("mkfs.btrfs").args(self.args).output()?)
+    }
+}
+
+impl super::formatter::FilesystemFormatter for Formatter {
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn rootdir(&self) -> Option<&Path> {
+        self.rootdir.as_deref()
+    }
+
+    fn format(self, device: &Path) -> Result<Output> {
+        Formatter::format(self, device)
     }
 }
@@ -0,0 +1,19 @@
+//! # FilesystemFormatter
+//! A common interface over filesystem-creation backends, so callers don't need
+//! to care whether a given backend shells out to an external tool (like
+//! [`super::format::Formatter`]) or builds the image entirely in-process (like
+//! [`super::fat::FatFormatter`]).
+
+use crate::Result;
+use std::path::Path;
+use std::process::Output;
+
+/// A backend that can write a filesystem image to `device`.
+pub trait FilesystemFormatter {
+    /// The volume label this formatter will apply.
+    fn label(&self) -> &str;
+    /// The directory whose contents will be copied into the new filesystem, if any.
+    fn rootdir(&self) -> Option<&Path>;
+    /// Formats `device`, consuming the formatter.
+    fn format(self, device: &Path) -> Result<Output>;
+}
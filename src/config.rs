@@ -206,10 +206,11 @@ impl Disk {
         self.disk_image_path = path.as_ref().to_path_buf();
         self
     }
-    ///# FIXME: Stringly typed API
-    pub fn disk_image_size(mut self, size: &str) -> Self {
-        self.disk_image_size = size.to_owned();
-        self
+    /// Accepts either a [`ByteSize`](crate::size::ByteSize) or a human string
+    /// like `8G`/`512M`/`1.5Gi`; rejects anything that doesn't parse.
+    pub fn disk_image_size(mut self, size: impl crate::size::IntoByteSize) -> crate::Result<Self> {
+        self.disk_image_size = size.into_byte_size()?.to_string();
+        Ok(self)
     }
     pub fn mount_directory<P: AsRef<Path>>(mut self, path: P) -> Self {
         self.mount_directory = path.as_ref().to_path_buf();
@@ -223,6 +224,24 @@ impl Disk {
         self.mount_options = cast_all(options);
         self
     }
+
+    /// Brings the disk subsystem up: creates `disk_image_path` sized to
+    /// `disk_image_size` if it's missing, formats it with btrfs if it isn't
+    /// already one, attaches it to a loop device, and mounts it at
+    /// `mount_directory` with `mount_options`.
+    pub fn up(&self) -> crate::Result<()> {
+        crate::disk::ensure_image(&self.disk_image_path, &self.disk_image_size)?;
+        crate::disk::ensure_formatted(&self.disk_image_path)?;
+        let loop_device = crate::disk::attach_loop(&self.disk_image_path)?;
+        crate::disk::mount(&loop_device, &self.mount_directory, &self.mount_options)
+    }
+
+    /// Tears the disk subsystem down: unmounts `mount_directory` and detaches
+    /// the loop device backing `disk_image_path`.
+    pub fn down(&self) -> crate::Result<()> {
+        crate::disk::teardown_mount(&self.mount_directory)?;
+        crate::disk::detach_loop(&self.disk_image_path)
+    }
 }
 
 /// [Configurator]
@@ -282,6 +301,31 @@ impl Configurator {
         self.systemd_directory = path.as_ref().to_path_buf();
         self
     }
+
+    /// Renders and installs rover's daemon unit, then drives `service`'s unit
+    /// states (stop `stop_units`, mask `mask_units`, restart `restart_units`).
+    /// The installed unit is linked into `systemd_directory` but not enabled,
+    /// so nothing here starts it at boot.
+    #[cfg(feature = "systemd")]
+    pub fn install(&self, service: &Service) -> crate::Result<()> {
+        crate::systemd::install_files(&self.service_directory, &self.systemd_directory)?;
+        crate::systemd::stop_units(&service.stop_units)?;
+        crate::systemd::mask_units(&service.mask_units)?;
+        crate::systemd::restart_units(&service.restart_units)?;
+        Ok(())
+    }
+
+    /// Reverses [`Configurator::install`]: unmasks and restarts the units it
+    /// touched, starts back up the units it stopped, then removes the
+    /// rendered unit and script.
+    #[cfg(feature = "systemd")]
+    pub fn uninstall(&self, service: &Service) -> crate::Result<()> {
+        crate::systemd::unmask_units(&service.mask_units)?;
+        crate::systemd::restart_units(&service.restart_units)?;
+        crate::systemd::start_units(&service.stop_units)?;
+        crate::systemd::uninstall_files(&self.service_directory, &self.systemd_directory)?;
+        Ok(())
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
@@ -319,14 +363,471 @@ impl Default for Config {
     }
 }
 
+/// Folds multiple partial config layers (built-in defaults, system file, user
+/// file, CLI overrides, ...) into one effective [`Config`] in precedence order.
+pub mod merge {
+    use super::{Common, Config, Configurator, Disk, Overlay, Service};
+    #[cfg(feature = "serde")]
+    use serde::Deserialize;
+    use std::path::PathBuf;
+
+    /// Types that can be folded together: `other` unconditionally replaces
+    /// `self` field-by-field. `Common`/`Service`/`Disk`/`Overlay`/`Configurator`
+    /// hold resolved values, not `Option`s, so `merge` itself can't tell "the
+    /// caller set this" from "this is just whatever was already there" —
+    /// that distinction is [`ConfigLayer::fold_into`]'s job: it resolves each
+    /// unset field back to the accumulator's current value *before* calling
+    /// `merge`, so the net effect is "the layer overrides only what it set".
+    pub trait Merge {
+        /// Fold `other` into `self`, `other` taking precedence field-by-field.
+        fn merge(&mut self, other: Self);
+    }
+
+    impl Merge for Common {
+        fn merge(&mut self, other: Self) {
+            self.base_directory = other.base_directory;
+            self.directories = other.directories;
+        }
+    }
+
+    impl Merge for Service {
+        fn merge(&mut self, other: Self) {
+            self.mask_units = other.mask_units;
+            self.restart_units = other.restart_units;
+            self.stop_units = other.stop_units;
+        }
+    }
+
+    impl Merge for Disk {
+        fn merge(&mut self, other: Self) {
+            self.disk_image_path = other.disk_image_path;
+            self.disk_image_size = other.disk_image_size;
+            self.mount_directory = other.mount_directory;
+            self.mount_options = other.mount_options;
+        }
+    }
+
+    impl Merge for Overlay {
+        fn merge(&mut self, other: Self) {
+            self.overlay_directory = other.overlay_directory;
+        }
+    }
+
+    impl Merge for Configurator {
+        fn merge(&mut self, other: Self) {
+            self.install_directory = other.install_directory;
+            self.logfile = other.logfile;
+            self.service_directory = other.service_directory;
+            self.systemd_directory = other.systemd_directory;
+        }
+    }
+
+    impl Merge for Config {
+        fn merge(&mut self, other: Self) {
+            self.common.merge(other.common);
+            self.configurator.merge(other.configurator);
+            self.disk.merge(other.disk);
+            self.overlay.merge(other.overlay);
+            self.service.merge(other.service);
+        }
+    }
+
+    /// How a higher-precedence layer's `Vec` should combine with what's
+    /// already been accumulated.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum ListPolicy {
+        /// The higher-precedence layer's list replaces the accumulated one.
+        Replace,
+        /// The higher-precedence layer's items are appended to the accumulated list.
+        Append,
+    }
+
+    impl Default for ListPolicy {
+        fn default() -> Self {
+            ListPolicy::Replace
+        }
+    }
+
+    fn apply_list<T>(dst: Vec<T>, src: Option<Vec<T>>, policy: ListPolicy) -> Vec<T> {
+        match (src, policy) {
+            (None, _) => dst,
+            (Some(src), ListPolicy::Replace) => src,
+            (Some(src), ListPolicy::Append) => dst.into_iter().chain(src).collect(),
+        }
+    }
+
+    /// An all-`Option` mirror of [`Common`], so a layer can tell "unset" apart from "default".
+    #[cfg_attr(feature = "serde", derive(Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[derive(Clone, Debug, Default)]
+    pub struct CommonLayer {
+        pub base_directory: Option<PathBuf>,
+        pub directories: Option<Vec<PathBuf>>,
+    }
+
+    /// An all-`Option` mirror of [`Service`].
+    #[cfg_attr(feature = "serde", derive(Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[derive(Clone, Debug, Default)]
+    pub struct ServiceLayer {
+        pub mask_units: Option<Vec<String>>,
+        pub restart_units: Option<Vec<String>>,
+        pub stop_units: Option<Vec<String>>,
+    }
+
+    /// An all-`Option` mirror of [`Disk`].
+    #[cfg_attr(feature = "serde", derive(Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[derive(Clone, Debug, Default)]
+    pub struct DiskLayer {
+        pub disk_image_path: Option<PathBuf>,
+        pub disk_image_size: Option<String>,
+        pub mount_directory: Option<PathBuf>,
+        pub mount_options: Option<Vec<String>>,
+    }
+
+    /// An all-`Option` mirror of [`Overlay`].
+    #[cfg_attr(feature = "serde", derive(Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[derive(Clone, Debug, Default)]
+    pub struct OverlayLayer {
+        pub overlay_directory: Option<PathBuf>,
+    }
+
+    /// An all-`Option` mirror of [`Configurator`].
+    #[cfg_attr(feature = "serde", derive(Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[derive(Clone, Debug, Default)]
+    pub struct ConfiguratorLayer {
+        pub install_directory: Option<PathBuf>,
+        pub service_directory: Option<PathBuf>,
+        pub systemd_directory: Option<PathBuf>,
+        pub logfile: Option<PathBuf>,
+    }
+
+    /// An all-`Option` mirror of [`Config`]; this is what each config layer on disk
+    /// deserializes into, so that a minimal file only needs to name the keys it changes.
+    #[cfg_attr(feature = "serde", derive(Deserialize))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[derive(Clone, Debug, Default)]
+    pub struct ConfigLayer {
+        pub common: CommonLayer,
+        pub configurator: ConfiguratorLayer,
+        pub disk: DiskLayer,
+        pub overlay: OverlayLayer,
+        pub service: ServiceLayer,
+    }
+
+    impl ConfigLayer {
+        /// Fold this layer onto `accumulator`, overriding only the fields this layer set
+        /// (falling back to `accumulator`'s current value for everything else), using
+        /// `policy` to decide how `Vec` fields combine, then applying the result via
+        /// [`Merge`].
+        pub fn fold_into(self, accumulator: &mut Config, policy: ListPolicy) {
+            accumulator.common.merge(Common {
+                base_directory: self
+                    .common
+                    .base_directory
+                    .unwrap_or_else(|| accumulator.common.base_directory.clone()),
+                directories: apply_list(
+                    accumulator.common.directories.clone(),
+                    self.common.directories,
+                    policy,
+                ),
+            });
+
+            accumulator.service.merge(Service {
+                mask_units: apply_list(
+                    accumulator.service.mask_units.clone(),
+                    self.service.mask_units,
+                    policy,
+                ),
+                restart_units: apply_list(
+                    accumulator.service.restart_units.clone(),
+                    self.service.restart_units,
+                    policy,
+                ),
+                stop_units: apply_list(
+                    accumulator.service.stop_units.clone(),
+                    self.service.stop_units,
+                    policy,
+                ),
+            });
+
+            accumulator.disk.merge(Disk {
+                disk_image_path: self
+                    .disk
+                    .disk_image_path
+                    .unwrap_or_else(|| accumulator.disk.disk_image_path.clone()),
+                disk_image_size: self
+                    .disk
+                    .disk_image_size
+                    .unwrap_or_else(|| accumulator.disk.disk_image_size.clone()),
+                mount_directory: self
+                    .disk
+                    .mount_directory
+                    .unwrap_or_else(|| accumulator.disk.mount_directory.clone()),
+                mount_options: apply_list(
+                    accumulator.disk.mount_options.clone(),
+                    self.disk.mount_options,
+                    policy,
+                ),
+            });
+
+            accumulator.overlay.merge(Overlay {
+                overlay_directory: self
+                    .overlay
+                    .overlay_directory
+                    .unwrap_or_else(|| accumulator.overlay.overlay_directory.clone()),
+            });
+
+            accumulator.configurator.merge(Configurator {
+                install_directory: self
+                    .configurator
+                    .install_directory
+                    .unwrap_or_else(|| accumulator.configurator.install_directory.clone()),
+                service_directory: self
+                    .configurator
+                    .service_directory
+                    .unwrap_or_else(|| accumulator.configurator.service_directory.clone()),
+                systemd_directory: self
+                    .configurator
+                    .systemd_directory
+                    .unwrap_or_else(|| accumulator.configurator.systemd_directory.clone()),
+                logfile: self
+                    .configurator
+                    .logfile
+                    .unwrap_or_else(|| accumulator.configurator.logfile.clone()),
+            });
+        }
+    }
+}
+
+/// Last-mile overrides (CLI flags, environment variables) applied on top of
+/// whatever the file layers produced.
+pub mod overrides {
+    use super::Config;
+    use std::env::var_os;
+    use std::path::PathBuf;
+
+    /// Optional fields that, when present, win over every config-file layer.
+    ///
+    /// Populate this from command-line flags and/or [`ConfigOverride::from_env`],
+    /// then apply it last via [`Config::apply_overrides`].
+    #[derive(Clone, Debug, Default, Eq, PartialEq)]
+    pub struct ConfigOverride {
+        pub base_directory: Option<PathBuf>,
+        pub overlay_directory: Option<PathBuf>,
+        pub disk_image_path: Option<PathBuf>,
+        pub disk_image_size: Option<String>,
+    }
+
+    impl ConfigOverride {
+        /// Reads overrides from `ROVER_*` environment variables, leaving a field
+        /// `None` (and thus the file value untouched) when its variable is unset.
+        ///
+        /// | Variable                 | Field                        |
+        /// |--------------------------|-------------------------------|
+        /// | `ROVER_BASE_DIRECTORY`   | `common.base_directory`       |
+        /// | `ROVER_OVERLAY_DIRECTORY`| `overlay.overlay_directory`   |
+        /// | `ROVER_DISK_IMAGE_PATH`  | `disk.disk_image_path`        |
+        /// | `ROVER_DISK_IMAGE_SIZE`  | `disk.disk_image_size`        |
+        pub fn from_env() -> Self {
+            ConfigOverride {
+                base_directory: var_os("ROVER_BASE_DIRECTORY").map(PathBuf::from),
+                overlay_directory: var_os("ROVER_OVERLAY_DIRECTORY").map(PathBuf::from),
+                disk_image_path: var_os("ROVER_DISK_IMAGE_PATH").map(PathBuf::from),
+                disk_image_size: var_os("ROVER_DISK_IMAGE_SIZE")
+                    .map(|v| v.to_string_lossy().into_owned()),
+            }
+        }
+    }
+
+    impl Config {
+        /// Applies `ov` on top of `self`, overriding only the fields `ov` actually set.
+        /// This is meant to run last, after [`Config::load`] or [`Config::load_layered`].
+        pub fn apply_overrides(&mut self, ov: &ConfigOverride) {
+            if let Some(base_directory) = &ov.base_directory {
+                self.common.base_directory = base_directory.clone();
+            }
+            if let Some(overlay_directory) = &ov.overlay_directory {
+                self.overlay.overlay_directory = overlay_directory.clone();
+            }
+            if let Some(disk_image_path) = &ov.disk_image_path {
+                self.disk.disk_image_path = disk_image_path.clone();
+            }
+            if let Some(disk_image_size) = &ov.disk_image_size {
+                self.disk.disk_image_size = disk_image_size.clone();
+            }
+        }
+    }
+}
+
+/// A dotted-path `get`/`set` accessor over [`Config`], so callers like a
+/// `rover config set disk.disk_image_size 16G` subcommand don't need a bespoke
+/// match arm of their own per field.
+pub mod path {
+    use super::Config;
+    use crate::{Error::ArgumentError, Result};
+    use std::path::PathBuf;
+
+    fn split_field(path: &str) -> Result<(&str, &str)> {
+        path.split_once('.')
+            .ok_or_else(|| ArgumentError(format!("{path:?} is not a section.field path")))
+    }
+
+    fn split_list(value: &str) -> Vec<String> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    impl Config {
+        /// Reads the field addressed by a dotted path like `disk.mount_options`,
+        /// rendered as a string (paths via `Display`, lists comma-joined).
+        pub fn get(&self, path: &str) -> Result<String> {
+            let (section, field) = split_field(path)?;
+            let value = match (section, field) {
+                ("common", "base_directory") => self.common.base_directory.display().to_string(),
+                ("common", "directories") => split_list_display(&self.common.directories),
+                ("service", "mask_units") => self.service.mask_units.join(","),
+                ("service", "restart_units") => self.service.restart_units.join(","),
+                ("service", "stop_units") => self.service.stop_units.join(","),
+                ("disk", "disk_image_path") => self.disk.disk_image_path.display().to_string(),
+                ("disk", "disk_image_size") => self.disk.disk_image_size.clone(),
+                ("disk", "mount_directory") => self.disk.mount_directory.display().to_string(),
+                ("disk", "mount_options") => self.disk.mount_options.join(","),
+                ("overlay", "overlay_directory") => {
+                    self.overlay.overlay_directory.display().to_string()
+                }
+                ("configurator", "install_directory") => {
+                    self.configurator.install_directory.display().to_string()
+                }
+                ("configurator", "service_directory") => {
+                    self.configurator.service_directory.display().to_string()
+                }
+                ("configurator", "systemd_directory") => {
+                    self.configurator.systemd_directory.display().to_string()
+                }
+                ("configurator", "logfile") => self.configurator.logfile.display().to_string(),
+                _ => return Err(ArgumentError(format!("unknown config path: {path:?}"))),
+            };
+            Ok(value)
+        }
+
+        /// Writes `value` to the field addressed by a dotted path like
+        /// `disk.disk_image_size`, coercing it into the field's type.
+        pub fn set(&mut self, path: &str, value: &str) -> Result<()> {
+            let (section, field) = split_field(path)?;
+            match (section, field) {
+                ("common", "base_directory") => self.common.base_directory = PathBuf::from(value),
+                ("common", "directories") => {
+                    self.common.directories = split_list(value).into_iter().map(PathBuf::from).collect()
+                }
+                ("service", "mask_units") => self.service.mask_units = split_list(value),
+                ("service", "restart_units") => self.service.restart_units = split_list(value),
+                ("service", "stop_units") => self.service.stop_units = split_list(value),
+                ("disk", "disk_image_path") => self.disk.disk_image_path = PathBuf::from(value),
+                ("disk", "disk_image_size") => {
+                    // Validate only; keep the user's own spelling (`16G`) instead of
+                    // rewriting it to `ByteSize`'s canonical binary-suffixed form.
+                    let _: crate::size::ByteSize = value.parse()?;
+                    self.disk.disk_image_size = value.to_owned();
+                }
+                ("disk", "mount_directory") => self.disk.mount_directory = PathBuf::from(value),
+                ("disk", "mount_options") => self.disk.mount_options = split_list(value),
+                ("overlay", "overlay_directory") => {
+                    self.overlay.overlay_directory = PathBuf::from(value)
+                }
+                ("configurator", "install_directory") => {
+                    self.configurator.install_directory = PathBuf::from(value)
+                }
+                ("configurator", "service_directory") => {
+                    self.configurator.service_directory = PathBuf::from(value)
+                }
+                ("configurator", "systemd_directory") => {
+                    self.configurator.systemd_directory = PathBuf::from(value)
+                }
+                ("configurator", "logfile") => self.configurator.logfile = PathBuf::from(value),
+                _ => return Err(ArgumentError(format!("unknown config path: {path:?}"))),
+            }
+            Ok(())
+        }
+    }
+
+    fn split_list_display(paths: &[PathBuf]) -> String {
+        paths
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
 pub mod io {
     use super::Config;
     use crate::{Error::ArgumentError, Result};
     use std::{
-        fs::{read_to_string, File},
+        fs::{self, File, OpenOptions},
         io::prelude::*,
-        path::Path,
+        path::{Path, PathBuf},
     };
+    #[cfg(unix)]
+    use std::os::unix::fs::OpenOptionsExt;
+
+    /// RAII guard around a sibling temporary file: unlinks it on drop unless
+    /// [`TempFile::commit`] has already renamed it into place.
+    struct TempFile {
+        path: PathBuf,
+        committed: bool,
+    }
+
+    impl TempFile {
+        /// Opens a fresh, privately-readable temp file next to `target`.
+        fn create(target: &Path) -> Result<(Self, File)> {
+            let dir = target.parent().filter(|p| !p.as_os_str().is_empty());
+            let file_name = target
+                .file_name()
+                .ok_or_else(|| ArgumentError(format!("{target:?} has no file name")))?;
+            let tmp_name = format!(".{}.tmp{}", file_name.to_string_lossy(), std::process::id());
+            let path = match dir {
+                Some(dir) => dir.join(tmp_name),
+                None => PathBuf::from(tmp_name),
+            };
+
+            let mut options = OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            options.mode(0o600);
+            let file = options.open(&path)?;
+
+            Ok((
+                TempFile {
+                    path,
+                    committed: false,
+                },
+                file,
+            ))
+        }
+
+        /// Renames the temp file over `target`, making the write visible atomically.
+        fn commit(mut self, target: &Path) -> Result<()> {
+            fs::rename(&self.path, target)?;
+            self.committed = true;
+            Ok(())
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            if !self.committed {
+                let _ = fs::remove_file(&self.path);
+            }
+        }
+    }
 
     /// Represents the valid config formats (serde serializers).
     #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -342,13 +843,86 @@ pub mod io {
         Yaml,
     }
 
+    impl ConfigFormat {
+        /// Detects the format from a path's extension (`.toml`, `.json`,
+        /// `.ron`/`.ron5`, `.yaml`/`.yml`), erroring on an unknown extension or
+        /// a disabled feature.
+        pub fn from_extension<P: AsRef<Path>>(path: P) -> Result<Self> {
+            let extension = path
+                .as_ref()
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(str::to_ascii_lowercase);
+            match extension.as_deref() {
+                #[cfg(feature = "toml")]
+                Some("toml") => Ok(ConfigFormat::Toml),
+                #[cfg(feature = "json")]
+                Some("json") => Ok(ConfigFormat::Json),
+                #[cfg(feature = "ron")]
+                Some("ron") | Some("ron5") => Ok(ConfigFormat::Ron),
+                #[cfg(feature = "yaml")]
+                Some("yaml") | Some("yml") => Ok(ConfigFormat::Yaml),
+                Some(extension) => Err(ArgumentError(format!(
+                    "unknown or disabled config extension: {extension:?}"
+                ))),
+                None => Err(ArgumentError(format!(
+                    "{:?} has no extension to detect a config format from",
+                    path.as_ref()
+                ))),
+            }
+        }
+    }
+
+    /// Wraps a value together with the path (and format) it was loaded from, so
+    /// it can be saved back without repeating either.
+    #[derive(Clone, Debug)]
+    pub struct WithPath<T> {
+        inner: T,
+        path: PathBuf,
+        format: ConfigFormat,
+    }
+
+    impl<T> std::ops::Deref for WithPath<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.inner
+        }
+    }
+
+    impl<T> std::ops::DerefMut for WithPath<T> {
+        fn deref_mut(&mut self) -> &mut T {
+            &mut self.inner
+        }
+    }
+
+    impl WithPath<Config> {
+        /// Writes `self` back to the path and format it was opened with.
+        pub fn save(&self) -> Result<()> {
+            self.inner.save(&self.path, self.format)
+        }
+    }
+
     impl Config {
+        /// Opens `path`, auto-detecting the format from its extension, and
+        /// remembers both for a later [`WithPath::save`].
+        pub fn open<P: AsRef<Path>>(path: P) -> Result<WithPath<Config>> {
+            let path = path.as_ref().to_path_buf();
+            let format = ConfigFormat::from_extension(&path)?;
+            let inner = Config::load(&path, format)?;
+            Ok(WithPath {
+                inner,
+                path,
+                format,
+            })
+        }
+
         pub fn load<'a, P>(path: P, format: ConfigFormat) -> Result<Self>
         where
             P: AsRef<Path>,
         {
             use ConfigFormat::*;
-            let file = read_to_string(path)?;
+            let file = crate::fs::read_to_string(path)?;
             match format {
                 #[cfg(feature = "json")]
                 Json => Ok(serde_json::from_str(&file)?),
@@ -361,13 +935,72 @@ pub mod io {
                 _ => Err(ArgumentError("".to_owned())),
             }
         }
+
+        /// Loads a single layer (system file, user file, ...) as a partial
+        /// [`super::merge::ConfigLayer`], where an absent key simply means "unset".
+        fn load_layer<P>(path: P, format: ConfigFormat) -> Result<super::merge::ConfigLayer>
+        where
+            P: AsRef<Path>,
+        {
+            use ConfigFormat::*;
+            let file = crate::fs::read_to_string(path)?;
+            match format {
+                #[cfg(feature = "json")]
+                Json => Ok(serde_json::from_str(&file)?),
+                #[cfg(feature = "ron")]
+                Ron => Ok(ron::from_str(&file)?),
+                #[cfg(feature = "toml")]
+                Toml => Ok(toml::from_str(&file)?),
+                #[cfg(feature = "yaml")]
+                Yaml => Ok(serde_yaml::from_str(&file)?),
+                _ => Err(ArgumentError("".to_owned())),
+            }
+        }
+
+        /// Builds the effective config by folding `paths` onto [`Config::default`] in
+        /// precedence order (lowest first): each successfully-parsed layer only overrides
+        /// the keys it actually sets, so a minimal user file only has to name what it changes.
+        ///
+        /// `Vec` fields replace the accumulated list; use [`Config::load_layered_with_policy`]
+        /// to append instead.
+        pub fn load_layered(paths: &[(std::path::PathBuf, ConfigFormat)]) -> Result<Self> {
+            Self::load_layered_with_policy(paths, super::merge::ListPolicy::default())
+        }
+
+        /// Like [`Config::load_layered`], but lets the caller choose how higher-precedence
+        /// `Vec` fields combine with what's already been accumulated.
+        pub fn load_layered_with_policy(
+            paths: &[(std::path::PathBuf, ConfigFormat)],
+            policy: super::merge::ListPolicy,
+        ) -> Result<Self> {
+            let mut config = Config::default();
+            for (path, format) in paths {
+                if let Ok(layer) = Self::load_layer(path, *format) {
+                    layer.fold_into(&mut config, policy);
+                }
+            }
+            Ok(config)
+        }
         /// Saves `Config` to a file at `path`, in format `format`.
+        ///
+        /// The write goes to a sibling temp file first, which is flushed, fsynced,
+        /// and `rename`d over `path` so a crash or serialization error never leaves
+        /// a truncated config behind. On Unix the temp file is created `0o600` so
+        /// the config is never briefly world-readable.
         pub fn save<P>(&self, path: P, format: ConfigFormat) -> Result<()>
         where
             P: AsRef<Path>,
         {
-            let mut file = File::create(path)?;
-            Ok(write!(file, "{}\n", self.to_string_pretty(format)?)?)
+            let path = path.as_ref();
+            let rendered = self.to_string_pretty(format)?;
+
+            let (tmp, mut file) = TempFile::create(path)?;
+            write!(file, "{rendered}\n")?;
+            file.flush()?;
+            file.sync_all()?;
+            drop(file);
+
+            tmp.commit(path)
         }
 
         /// Deserializes `Config` to a pretty string.
@@ -389,4 +1022,54 @@ pub mod io {
             })
         }
     }
+
+    /// Reads any serde-deserializable type from `path`, auto-detecting the
+    /// format from its extension exactly like [`Config::open`] does. Lets
+    /// callers keep overmount definitions in whichever format happens to be
+    /// compiled in, without matching extensions themselves.
+    pub fn load_config<T: serde::de::DeserializeOwned>(path: impl AsRef<Path>) -> Result<T> {
+        use ConfigFormat::*;
+        let path = path.as_ref();
+        let format = ConfigFormat::from_extension(path)?;
+        let file = crate::fs::read_to_string(path)?;
+        Ok(match format {
+            #[cfg(feature = "json")]
+            Json => serde_json::from_str(&file)?,
+            #[cfg(feature = "ron")]
+            Ron => ron::from_str(&file)?,
+            #[cfg(feature = "toml")]
+            Toml => toml::from_str(&file)?,
+            #[cfg(feature = "yaml")]
+            Yaml => serde_yaml::from_str(&file)?,
+            _ => return Err(ArgumentError("".to_owned())),
+        })
+    }
+
+    /// Writes `value` to `path`, auto-detecting the format from its extension,
+    /// atomically and with the same permission-aware temp-file dance as
+    /// [`Config::save`].
+    pub fn save_config<T: serde::Serialize>(path: impl AsRef<Path>, value: &T) -> Result<()> {
+        use ConfigFormat::*;
+        let path = path.as_ref();
+        let format = ConfigFormat::from_extension(path)?;
+        let rendered = match format {
+            #[cfg(feature = "json")]
+            Json => serde_json::ser::to_string_pretty(value)?,
+            #[cfg(feature = "ron")]
+            Ron => ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())?,
+            #[cfg(feature = "toml")]
+            Toml => toml::ser::to_string_pretty(value)?,
+            #[cfg(feature = "yaml")]
+            Yaml => serde_yaml::to_string(value)?,
+            _ => return Err(ArgumentError("".to_owned())),
+        };
+
+        let (tmp, mut file) = TempFile::create(path)?;
+        write!(file, "{rendered}\n")?;
+        file.flush()?;
+        file.sync_all()?;
+        drop(file);
+
+        tmp.commit(path)
+    }
 }
@@ -0,0 +1,139 @@
+//! # disk
+//! Backing implementation for [`crate::config::Disk::up`] / [`crate::config::Disk::down`]:
+//! creates and formats the btrfs loopback image, attaches/detaches the loop device, and
+//! mounts/unmounts it at the configured directory.
+
+use crate::{Error, Result};
+use std::fs::OpenOptions;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use sys_mount::{unmount, Mount, UnmountFlags};
+
+/// Offset of the btrfs superblock's magic number within the filesystem.
+const BTRFS_SUPERBLOCK_OFFSET: u64 = 0x10_040;
+/// The magic bytes a btrfs superblock starts with.
+const BTRFS_MAGIC: &[u8; 8] = b"_BHRfS_M";
+
+/// Creates `path` as a sparse file of `size` if it doesn't already exist.
+pub(crate) fn ensure_image(path: &Path, size: &str) -> Result<()> {
+    if path.try_exists()? {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let byte_size: crate::size::ByteSize = size.parse()?;
+    let file = OpenOptions::new().write(true).create(true).open(path)?;
+    file.set_len(byte_size.as_bytes())?;
+    Ok(())
+}
+
+/// Formats `path` with btrfs, unless it already looks like one.
+pub(crate) fn ensure_formatted(path: &Path) -> Result<()> {
+    if is_btrfs(path)? {
+        return Ok(());
+    }
+    let output = crate::btrfs::format::Formatter::options()
+        .finalize()?
+        .format(path)?;
+    if !output.status.success() {
+        return Err(Error::ArgumentError(format!(
+            "mkfs.btrfs failed on {path:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+    Ok(())
+}
+
+fn is_btrfs(path: &Path) -> Result<bool> {
+    let mut file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(_) => return Ok(false),
+    };
+    if file.seek(SeekFrom::Start(BTRFS_SUPERBLOCK_OFFSET)).is_err() {
+        return Ok(false);
+    }
+    let mut magic = [0u8; 8];
+    if file.read_exact(&mut magic).is_err() {
+        return Ok(false);
+    }
+    Ok(&magic == BTRFS_MAGIC)
+}
+
+/// Attaches `image` to a free loop device and returns its `/dev/loopN` path.
+pub(crate) fn attach_loop(image: &Path) -> Result<PathBuf> {
+    let control = loopdev::LoopControl::open()?;
+    let device = control.next_free()?;
+    device.attach_file(image)?;
+    device
+        .path()
+        .ok_or_else(|| Error::ArgumentError("loop device has no path".to_owned()))
+}
+
+/// Finds the loop device currently backed by `image`, if any.
+fn find_loop_device(image: &Path) -> Result<Option<PathBuf>> {
+    let image = std::fs::canonicalize(image)?;
+    for entry in std::fs::read_dir("/sys/block")? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.starts_with("loop") {
+            continue;
+        }
+        let backing_file = entry.path().join("loop/backing_file");
+        let Ok(backing) = std::fs::read_to_string(&backing_file) else {
+            continue;
+        };
+        if std::fs::canonicalize(backing.trim()).ok().as_deref() == Some(image.as_path()) {
+            return Ok(Some(Path::new("/dev").join(&*name)));
+        }
+    }
+    Ok(None)
+}
+
+/// Detaches the loop device backed by `image`, if one is currently attached.
+pub(crate) fn detach_loop(image: &Path) -> Result<()> {
+    if let Some(device_path) = find_loop_device(image)? {
+        loopdev::LoopDevice::open(&device_path)?.detach()?;
+    }
+    Ok(())
+}
+
+/// `mount(8)`/fstab options that util-linux interprets itself and never
+/// forwards to the kernel; passing them through as btrfs mount data makes
+/// the kernel reject the mount with `EINVAL`. `loop` especially has no
+/// business here: `attach_loop` already did that job before we mount.
+const USERSPACE_ONLY_OPTIONS: &[&str] = &[
+    "loop", "auto", "noauto", "user", "nouser", "users", "owner", "group", "_netdev", "nofail",
+    "defaults",
+];
+
+/// Joins `options` into the comma-separated string the kernel expects as
+/// mount data, dropping [`USERSPACE_ONLY_OPTIONS`] first.
+fn kernel_mount_data(options: &[String]) -> String {
+    options
+        .iter()
+        .filter(|option| {
+            let name = option.split('=').next().unwrap_or(option);
+            !USERSPACE_ONLY_OPTIONS.contains(&name)
+        })
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Mounts `device` at `destination` with the given btrfs mount options.
+pub(crate) fn mount(device: &Path, destination: &Path, options: &[String]) -> Result<()> {
+    crate::fs::create_dir_all(destination)?;
+    Mount::builder()
+        .fstype("btrfs")
+        .data(&kernel_mount_data(options))
+        .mount(device, destination)?;
+    Ok(())
+}
+
+/// Lazily unmounts `destination`.
+pub(crate) fn teardown_mount(destination: &Path) -> Result<()> {
+    unmount(destination, UnmountFlags::DETACH)?;
+    Ok(())
+}
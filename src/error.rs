@@ -1,6 +1,7 @@
 //! Contains all-encompassing error type for rover.
 //!
 
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// A specialized [`Result`] type for overmount errors.
@@ -10,6 +11,15 @@ pub type Result<T> = std::result::Result<T, crate::Error>;
 pub enum Error {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    /// Like [`Error::IoError`], but keeps the path that failed, so the
+    /// message says which config file or mount point couldn't be read
+    /// instead of a bare "No such file or directory".
+    #[error("{path:?}: {source}")]
+    PathIo {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
     #[error(transparent)]
     MountError(#[from] libmount::Error),
     #[error("{0}")]
@@ -32,4 +42,43 @@ pub enum Error {
     #[cfg(feature = "yaml")]
     #[error(transparent)]
     YamlError(#[from] serde_yaml::Error),
+    #[cfg(feature = "systemd")]
+    #[error(transparent)]
+    SystemdError(#[from] zbus::Error),
+    #[cfg(feature = "fatfs")]
+    #[error(transparent)]
+    FatError(#[from] fatfs::Error<std::io::Error>),
+    #[error("{option} requires btrfs-progs >= {required_version}, found {found_version}")]
+    Unsupported {
+        option: String,
+        required_version: String,
+        found_version: String,
+    },
+    /// Added by [`ResultExt::context`] so a deep mount or config failure reads
+    /// as a layered story instead of a lone errno string.
+    #[error("{msg}: {source}")]
+    Context {
+        msg: String,
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Borrows anyhow's `.context()` ergonomics for [`crate::Result`]: attaches a
+/// human-readable message to an error without discarding it, so
+/// `mount(dev, dst).context("mounting overlay lower dir")?` reads as
+/// `"mounting overlay lower dir: <underlying>"` while `source()` still chains
+/// down to the original error.
+pub trait ResultExt<T> {
+    /// Wraps the error in [`Error::Context`] with `msg`, if there is one.
+    fn context(self, msg: impl Into<String>) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn context(self, msg: impl Into<String>) -> Result<T> {
+        self.map_err(|source| Error::Context {
+            msg: msg.into(),
+            source: Box::new(source),
+        })
+    }
 }
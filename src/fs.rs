@@ -0,0 +1,25 @@
+//! Small internal wrapper around [`std::fs`] (in the spirit of `fs-err`):
+//! the handful of calls used for config loading and mount-point setup, each
+//! annotated with the path that failed instead of a bare [`std::io::Error`].
+
+use crate::{Error, Result};
+use std::path::Path;
+
+fn annotate(path: &Path) -> impl FnOnce(std::io::Error) -> Error + '_ {
+    move |source| Error::PathIo {
+        path: path.to_owned(),
+        source,
+    }
+}
+
+/// Like [`std::fs::read_to_string`], but the error names `path`.
+pub(crate) fn read_to_string(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    std::fs::read_to_string(path).map_err(annotate(path))
+}
+
+/// Like [`std::fs::create_dir_all`], but the error names `path`.
+pub(crate) fn create_dir_all(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    std::fs::create_dir_all(path).map_err(annotate(path))
+}
@@ -1,11 +1,18 @@
 //! GG EZ opaque overlay mounts.
 //! For when you don't care about the implementation
 
+pub mod btrfs;
 pub mod config;
+pub mod disk;
 pub mod error;
+mod fs;
+pub mod mounts;
 pub mod overlay;
+pub mod size;
+#[cfg(feature = "systemd")]
+pub mod systemd;
 
-pub use error::{Error, Result};
+pub use error::{Error, Result, ResultExt};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,111 @@
+//! # mounts
+//! Enumerates the active mount table (`/proc/mounts`) so overmount operations
+//! can check whether a destination is already mounted before mounting over
+//! it, and lists btrfs subvolumes on a device, without shelling out just to
+//! answer "is this mounted?".
+
+use crate::Result;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A single entry from `/proc/mounts`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MountEntry {
+    pub source: String,
+    pub target: PathBuf,
+    pub fstype: String,
+    pub options: Vec<String>,
+}
+
+impl MountEntry {
+    /// The value of a comma-separated `key=value` mount option (e.g. `subvol`
+    /// or `subvolid`, which btrfs reports alongside `rw,relatime,...`), if set.
+    pub fn option(&self, key: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find_map(|option| option.strip_prefix(key)?.strip_prefix('='))
+    }
+}
+
+/// Parses `/proc/mounts` into its entries.
+fn read_mounts() -> Result<Vec<MountEntry>> {
+    Ok(parse_mounts(&std::fs::read_to_string("/proc/mounts")?))
+}
+
+fn parse_mounts(text: &str) -> Vec<MountEntry> {
+    text.lines().filter_map(parse_mount_line).collect()
+}
+
+fn parse_mount_line(line: &str) -> Option<MountEntry> {
+    let mut fields = line.split_whitespace();
+    let source = fields.next()?.to_owned();
+    let target = PathBuf::from(unescape(fields.next()?));
+    let fstype = fields.next()?.to_owned();
+    let options = fields.next()?.split(',').map(str::to_owned).collect();
+    Some(MountEntry {
+        source,
+        target,
+        fstype,
+        options,
+    })
+}
+
+/// Undoes the `\040`-style octal escaping `/proc/mounts` uses for spaces and
+/// other characters in paths.
+fn unescape(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        let octal: String = chars.by_ref().take(3).collect();
+        match u8::from_str_radix(&octal, 8) {
+            Ok(byte) => result.push(byte as char),
+            Err(_) => {
+                result.push(c);
+                result.push_str(&octal);
+            }
+        }
+    }
+    result
+}
+
+/// Whether `path` is currently a mount point, guarding against double-mounts.
+pub fn is_mounted(path: &Path) -> Result<bool> {
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_owned());
+    Ok(read_mounts()?.iter().any(|entry| entry.target == path))
+}
+
+/// A btrfs subvolume, as reported by `btrfs subvolume list`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subvolume {
+    pub id: u64,
+    pub path: String,
+}
+
+/// Lists the subvolumes present on `device`, so a `subvol=` name can be
+/// resolved to the id the btrfs module needs to mount it.
+pub fn subvolumes(device: &Path) -> Result<Vec<Subvolume>> {
+    let output = Command::new("btrfs")
+        .args(["subvolume", "list"])
+        .arg(device)
+        .output()?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(parse_subvolume_line)
+        .collect())
+}
+
+/// Parses a line of `btrfs subvolume list` output, e.g.
+/// `"ID 256 gen 10 top level 5 path @home"`.
+fn parse_subvolume_line(line: &str) -> Option<Subvolume> {
+    let mut fields = line.split_whitespace();
+    if fields.next()? != "ID" {
+        return None;
+    }
+    let id = fields.next()?.parse().ok()?;
+    let path = line.split_once(" path ")?.1.to_owned();
+    Some(Subvolume { id, path })
+}
@@ -0,0 +1,110 @@
+//! Typed byte sizes, parsed from and rendered back to human strings like
+//! `8G` (decimal) or `8Gi` (binary).
+
+use crate::{Error, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// An exact byte count. Replaces the "stringly typed" `disk_image_size` with
+/// something that's actually validated and computed against.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Wraps a raw byte count.
+    pub const fn from_bytes(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+
+    /// Returns the exact byte count.
+    pub const fn as_bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = Error;
+
+    /// Parses human strings like `8G`, `512M`, `1.5Gi`, or a raw `1048576` with
+    /// no suffix. Suffixes are case-insensitive; `Ki`/`Mi`/`Gi`/`Ti` are binary
+    /// (powers of 1024), `K`/`M`/`G`/`T` are decimal (powers of 1000).
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(Error::ArgumentError("empty byte size".to_owned()));
+        }
+
+        let split_at = trimmed
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(trimmed.len());
+        let (number, suffix) = trimmed.split_at(split_at);
+
+        let value: f64 = number
+            .parse()
+            .map_err(|_| Error::ArgumentError(format!("invalid byte size: {s:?}")))?;
+
+        let multiplier: u64 = match suffix.to_ascii_lowercase().as_str() {
+            "" => 1,
+            "k" => 1_000,
+            "ki" => 1024,
+            "m" => 1_000_000,
+            "mi" => 1024 * 1024,
+            "g" => 1_000_000_000,
+            "gi" => 1024 * 1024 * 1024,
+            "t" => 1_000_000_000_000,
+            "ti" => 1024_u64.pow(4),
+            _ => {
+                return Err(Error::ArgumentError(format!(
+                    "unknown byte size suffix: {suffix:?}"
+                )))
+            }
+        };
+
+        Ok(ByteSize((value * multiplier as f64).round() as u64))
+    }
+}
+
+impl TryFrom<&str> for ByteSize {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+/// Lets [`crate::config::Disk::disk_image_size`] accept either an already-typed
+/// [`ByteSize`] or a human string, both fallibly.
+pub trait IntoByteSize {
+    fn into_byte_size(self) -> Result<ByteSize>;
+}
+
+impl IntoByteSize for ByteSize {
+    fn into_byte_size(self) -> Result<ByteSize> {
+        Ok(self)
+    }
+}
+
+impl IntoByteSize for &str {
+    fn into_byte_size(self) -> Result<ByteSize> {
+        self.parse()
+    }
+}
+
+impl fmt::Display for ByteSize {
+    /// Renders the canonical binary-suffixed form, falling back to raw bytes
+    /// when the value isn't a clean multiple of any unit.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [(u64, &str); 4] = [
+            (1024_u64.pow(4), "Ti"),
+            (1024_u64.pow(3), "Gi"),
+            (1024_u64.pow(2), "Mi"),
+            (1024, "Ki"),
+        ];
+        for (factor, suffix) in UNITS {
+            if self.0 != 0 && self.0 % factor == 0 {
+                return write!(f, "{}{suffix}", self.0 / factor);
+            }
+        }
+        write!(f, "{}", self.0)
+    }
+}
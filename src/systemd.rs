@@ -0,0 +1,141 @@
+//! # systemd
+//! Renders rover's daemon unit, installs/uninstalls it, and drives the unit
+//! state transitions described by [`crate::config::Service`] over the
+//! `org.freedesktop.systemd1` D-Bus interface (no shelling out to `systemctl`).
+
+use crate::Result;
+use std::fs;
+use std::os::unix::fs::symlink;
+use std::path::Path;
+use zbus::blocking::Connection;
+use zbus::dbus_proxy;
+
+/// Name of rover's own systemd unit.
+pub const UNIT_NAME: &str = "rover.service";
+/// Name of the daemon script the unit execs.
+const DAEMON_SCRIPT_NAME: &str = "rover-daemon.sh";
+
+#[dbus_proxy(
+    interface = "org.freedesktop.systemd1.Manager",
+    default_service = "org.freedesktop.systemd1",
+    default_path = "/org/freedesktop/systemd1"
+)]
+trait SystemdManager {
+    fn start_unit(&self, name: &str, mode: &str) -> zbus::Result<zbus::zvariant::OwnedObjectPath>;
+    fn stop_unit(&self, name: &str, mode: &str) -> zbus::Result<zbus::zvariant::OwnedObjectPath>;
+    fn restart_unit(&self, name: &str, mode: &str)
+        -> zbus::Result<zbus::zvariant::OwnedObjectPath>;
+    fn mask_unit_files(
+        &self,
+        files: &[&str],
+        runtime: bool,
+        force: bool,
+    ) -> zbus::Result<(bool, Vec<(String, String, String)>)>;
+    fn unmask_unit_files(
+        &self,
+        files: &[&str],
+        runtime: bool,
+    ) -> zbus::Result<(bool, Vec<(String, String, String)>)>;
+    fn reload(&self) -> zbus::Result<()>;
+}
+
+fn manager() -> Result<SystemdManagerProxyBlocking<'static>> {
+    let connection = Connection::system()?;
+    Ok(SystemdManagerProxyBlocking::new(&connection)?)
+}
+
+fn render_daemon_script() -> String {
+    "#!/bin/sh\nexec rover --daemon\n".to_owned()
+}
+
+fn render_service_unit(exec_path: &Path) -> String {
+    format!(
+        "[Unit]\nDescription=rover overlay daemon\n\n[Service]\nType=simple\nExecStart={}\n\n[Install]\nWantedBy=multi-user.target\n",
+        exec_path.display()
+    )
+}
+
+/// Renders the daemon script and `.service` unit into `service_directory`, then
+/// symlinks the unit into `systemd_directory` and reloads systemd so the unit
+/// is known. The unit is not enabled: no `.wants` link is created for its
+/// `WantedBy=` target.
+pub(crate) fn install_files(service_directory: &Path, systemd_directory: &Path) -> Result<()> {
+    fs::create_dir_all(service_directory)?;
+    fs::create_dir_all(systemd_directory)?;
+
+    let script_path = service_directory.join(DAEMON_SCRIPT_NAME);
+    fs::write(&script_path, render_daemon_script())?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&script_path, fs::Permissions::from_mode(0o755))?;
+    }
+
+    let unit_path = service_directory.join(UNIT_NAME);
+    fs::write(&unit_path, render_service_unit(&script_path))?;
+
+    let link_path = systemd_directory.join(UNIT_NAME);
+    if link_path.exists() || link_path.symlink_metadata().is_ok() {
+        fs::remove_file(&link_path)?;
+    }
+    symlink(&unit_path, &link_path)?;
+
+    manager()?.reload()?;
+    Ok(())
+}
+
+/// Removes the unit symlink and the rendered script/unit from disk.
+pub(crate) fn uninstall_files(service_directory: &Path, systemd_directory: &Path) -> Result<()> {
+    let link_path = systemd_directory.join(UNIT_NAME);
+    if link_path.symlink_metadata().is_ok() {
+        fs::remove_file(&link_path)?;
+    }
+    let _ = fs::remove_file(service_directory.join(UNIT_NAME));
+    let _ = fs::remove_file(service_directory.join(DAEMON_SCRIPT_NAME));
+    manager()?.reload()?;
+    Ok(())
+}
+
+fn as_refs(units: &[String]) -> Vec<&str> {
+    units.iter().map(String::as_str).collect()
+}
+
+/// Stops each unit in `units` in listed order, returning at the first failure.
+pub(crate) fn stop_units(units: &[String]) -> Result<()> {
+    let manager = manager()?;
+    for unit in units {
+        manager.stop_unit(unit, "replace")?;
+    }
+    Ok(())
+}
+
+/// Starts each unit in `units`, reversing [`stop_units`].
+pub(crate) fn start_units(units: &[String]) -> Result<()> {
+    let manager = manager()?;
+    for unit in units {
+        manager.start_unit(unit, "replace")?;
+    }
+    Ok(())
+}
+
+/// Restarts (or starts, if not running) each unit in `units`.
+pub(crate) fn restart_units(units: &[String]) -> Result<()> {
+    let manager = manager()?;
+    for unit in units {
+        manager.restart_unit(unit, "replace")?;
+    }
+    Ok(())
+}
+
+/// Masks every unit in `units`, preventing it from being started.
+pub(crate) fn mask_units(units: &[String]) -> Result<()> {
+    manager()?.mask_unit_files(&as_refs(units), false, true)?;
+    Ok(())
+}
+
+/// Unmasks every unit in `units`, reversing [`mask_units`].
+pub(crate) fn unmask_units(units: &[String]) -> Result<()> {
+    manager()?.unmask_unit_files(&as_refs(units), false)?;
+    Ok(())
+}
+
@@ -53,4 +53,72 @@ mod config {
             Ok(())
         }
     }
+
+    mod path {
+        use super::*;
+
+        #[test]
+        fn get_set_round_trips() -> Result<()> {
+            let mut config = Config::from("/opt/rover");
+            config.set("disk.disk_image_size", "16G")?;
+            assert_eq!(config.get("disk.disk_image_size")?, "16G");
+
+            config.set("service.mask_units", "a.service, b.service")?;
+            assert_eq!(config.get("service.mask_units")?, "a.service,b.service");
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_path_errors() {
+            let config = Config::from("/opt/rover");
+            assert!(config.get("disk.nonexistent").is_err());
+        }
+    }
+}
+
+mod size {
+    use crate::size::ByteSize;
+
+    #[test]
+    fn no_suffix_is_raw_bytes() {
+        assert_eq!("1048576".parse::<ByteSize>().unwrap().as_bytes(), 1_048_576);
+    }
+
+    #[test]
+    fn decimal_suffix() {
+        assert_eq!("8G".parse::<ByteSize>().unwrap().as_bytes(), 8_000_000_000);
+    }
+
+    #[test]
+    fn binary_suffix() {
+        assert_eq!(
+            "8Gi".parse::<ByteSize>().unwrap().as_bytes(),
+            8 * 1024 * 1024 * 1024
+        );
+    }
+
+    #[test]
+    fn fractional_size() {
+        assert_eq!(
+            "1.5Gi".parse::<ByteSize>().unwrap().as_bytes(),
+            (1.5 * 1024.0 * 1024.0 * 1024.0) as u64
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_suffix() {
+        assert!("8Q".parse::<ByteSize>().is_err());
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!("not-a-size".parse::<ByteSize>().is_err());
+    }
+
+    #[test]
+    fn canonical_round_trip() {
+        let size: ByteSize = "8Gi".parse().unwrap();
+        assert_eq!(size.to_string(), "8Gi");
+        assert_eq!(size.to_string().parse::<ByteSize>().unwrap(), size);
+    }
 }